@@ -0,0 +1,109 @@
+//! Template-based rendering for [`Assertion`] results.
+//!
+//! This backs [`crate::grillon::LogSettings::Custom`], which lets callers
+//! describe their own one-line-per-assertion output instead of the built-in
+//! human-readable or JSON layouts. The idea mirrors a configurable access-log
+//! format string: a template made of `%`-prefixed placeholders, resolved
+//! against each [`Assertion`] as it is produced.
+use super::Assertion;
+use std::fmt::Debug;
+
+/// Placeholders recognized by [`render`], longest-match order doesn't matter
+/// here since none of them is a prefix of another.
+const PLACEHOLDERS: &[&str] = &["%part", "%predicate", "%left", "%right", "%result"];
+
+/// Renders a single [`Assertion`] by substituting the placeholders found in
+/// `template`.
+///
+/// Supported placeholders:
+/// - `%part`: the [`crate::dsl::Part`] being checked (status, headers, body, time)
+/// - `%predicate`: the [`crate::dsl::Predicate`] evaluated (e.g. `Is`, `Between`)
+/// - `%left`: the expected value, as held in [`Assertion::left`]
+/// - `%right`: the actual value
+/// - `%result`: `passed` or `failed`
+///
+/// Unknown `%`-sequences are left untouched so a typo in a template doesn't
+/// silently swallow output. Substitution is a single pass over `template`
+/// itself, so a placeholder-looking substring inside a resolved value (e.g.
+/// a response body containing the literal text `%result`) is never
+/// re-substituted.
+pub fn render<T: Debug>(template: &str, assertion: &Assertion<T>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    'outer: while !rest.is_empty() {
+        for placeholder in PLACEHOLDERS {
+            if let Some(remainder) = rest.strip_prefix(placeholder) {
+                output.push_str(&resolve(placeholder, assertion));
+                rest = remainder;
+                continue 'outer;
+            }
+        }
+
+        let mut chars = rest.chars();
+        output.push(chars.next().expect("rest is non-empty"));
+        rest = chars.as_str();
+    }
+
+    output
+}
+
+fn resolve<T: Debug>(placeholder: &str, assertion: &Assertion<T>) -> String {
+    match placeholder {
+        "%part" => format!("{:?}", assertion.part),
+        "%predicate" => format!("{:?}", assertion.predicate),
+        "%left" => format!("{:?}", assertion.left),
+        "%right" => format!("{:?}", assertion.right),
+        "%result" => (if assertion.passed { "passed" } else { "failed" }).to_string(),
+        _ => unreachable!("{placeholder} is not in PLACEHOLDERS"),
+    }
+}
+
+/// Writes one rendered line per [`Assertion`] to stdout, in the order they
+/// were produced.
+pub fn print_all<T: Debug>(template: &str, assertions: &[Assertion<T>]) {
+    for assertion in assertions {
+        println!("{}", render(template, assertion));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsl::{Part, Predicate};
+    use std::time::Duration;
+
+    #[test]
+    fn render_substitutes_every_placeholder() {
+        let assertion = Assertion {
+            part: Part::Status,
+            predicate: Predicate::Is,
+            left: 200,
+            right: 404,
+            passed: false,
+            elapsed: Duration::from_millis(12),
+            candidates: None,
+        };
+
+        let line = render("%part %predicate %left %right %result", &assertion);
+
+        assert_eq!(line, "Status Is 200 404 failed");
+    }
+
+    #[test]
+    fn render_does_not_resubstitute_placeholder_text_found_in_a_value() {
+        let assertion = Assertion {
+            part: Part::Body,
+            predicate: Predicate::Is,
+            left: "contains %result and %elapsed".to_string(),
+            right: "contains %result and %elapsed".to_string(),
+            passed: false,
+            elapsed: Duration::from_millis(12),
+            candidates: None,
+        };
+
+        let line = render("%left -> %result", &assertion);
+
+        assert_eq!(line, "\"contains %result and %elapsed\" -> failed");
+    }
+}
@@ -0,0 +1,96 @@
+//! Machine-readable JSON rendering for [`Assertion`] results.
+//!
+//! This backs [`crate::grillon::LogSettings::Json`]. There's no `serde`
+//! dependency in this crate, so the output is assembled by hand from the
+//! [`Debug`] representation of each field rather than a derived
+//! `Serialize` impl.
+use super::Assertion;
+use std::fmt::Debug;
+
+/// Renders a single [`Assertion`] as a JSON object.
+pub fn render<T: Debug>(assertion: &Assertion<T>) -> String {
+    let candidates = match &assertion.candidates {
+        Some(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(|value| json_string(&format!("{value:?}")))
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        None => "null".to_string(),
+    };
+
+    format!(
+        "{{\"part\":{},\"predicate\":{},\"left\":{},\"right\":{},\"passed\":{},\"elapsed_ms\":{},\"candidates\":{}}}",
+        json_string(&format!("{:?}", assertion.part)),
+        json_string(&format!("{:?}", assertion.predicate)),
+        json_string(&format!("{:?}", assertion.left)),
+        json_string(&format!("{:?}", assertion.right)),
+        assertion.passed,
+        assertion.elapsed.as_millis(),
+        candidates,
+    )
+}
+
+/// Escapes `value` and wraps it in double quotes, producing a JSON string
+/// literal.
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsl::{Part, Predicate};
+    use std::time::Duration;
+
+    #[test]
+    fn render_serializes_a_plain_assertion() {
+        let assertion = Assertion {
+            part: Part::Status,
+            predicate: Predicate::Is,
+            left: 200,
+            right: 404,
+            passed: false,
+            elapsed: Duration::from_millis(12),
+            candidates: None,
+        };
+
+        assert_eq!(
+            render(&assertion),
+            "{\"part\":\"Status\",\"predicate\":\"Is\",\"left\":\"200\",\"right\":\"404\",\
+             \"passed\":false,\"elapsed_ms\":12,\"candidates\":null}"
+        );
+    }
+
+    #[test]
+    fn render_serializes_the_candidate_set_for_one_of_assertions() {
+        let assertion = Assertion {
+            part: Part::Status,
+            predicate: Predicate::OneOf,
+            left: 404,
+            right: 404,
+            passed: true,
+            elapsed: Duration::default(),
+            candidates: Some(vec![200, 404, 500]),
+        };
+
+        assert_eq!(
+            render(&assertion),
+            "{\"part\":\"Status\",\"predicate\":\"OneOf\",\"left\":\"404\",\"right\":\"404\",\
+             \"passed\":true,\"elapsed_ms\":0,\"candidates\":[\"200\",\"404\",\"500\"]}"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Assertion primitives shared by every DSL: building an [`Assertion`] from
+//! a predicate and a response value, then logging the pass/fail result
+//! through the configured [`crate::grillon::LogSettings`].
+pub mod format;
+pub mod json;
+pub mod traits;
+
+use crate::{
+    dsl::{Part, Predicate},
+    grillon::LogSettings,
+};
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// The result of evaluating a single assertion against a response.
+#[derive(Debug, Clone)]
+pub struct Assertion<T> {
+    /// The part of the response this assertion was evaluated against.
+    pub part: Part,
+    /// The predicate that was evaluated.
+    pub predicate: Predicate,
+    /// The expected value. For a [`Predicate::Between`] assertion this is the
+    /// lower bound of the range only. For a [`Predicate::OneOf`] assertion,
+    /// which has no single expected value, this is the actual value and the
+    /// expected set is in `candidates`.
+    pub left: T,
+    /// The actual value.
+    pub right: T,
+    /// Whether the assertion passed.
+    pub passed: bool,
+    /// Time taken to evaluate the assertion.
+    pub elapsed: Duration,
+    /// The full set of candidate values for a [`Predicate::OneOf`] assertion.
+    /// `None` for every other predicate.
+    pub candidates: Option<Vec<T>>,
+}
+
+impl<T: Debug> Assertion<T> {
+    /// Logs this assertion according to the given [`LogSettings`] and
+    /// returns it unchanged so evaluation can continue to be chained.
+    pub fn assert(self, log_settings: &LogSettings) -> Self {
+        match log_settings {
+            LogSettings::Human => println!("{:?}", self),
+            LogSettings::Json => println!("{}", json::render(&self)),
+            LogSettings::Custom(template) => println!("{}", format::render(template, &self)),
+        }
+
+        self
+    }
+}
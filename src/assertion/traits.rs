@@ -0,0 +1,254 @@
+//! Traits implemented by response values (e.g. [`crate::StatusCode`]) to
+//! turn a comparison against an expected value into an [`Assertion`].
+use super::Assertion;
+use crate::dsl::{Part, Predicate};
+use crate::StatusCode;
+use std::time::Duration;
+
+/// Builds an assertion comparing the equality between two values.
+pub trait IsEq<T> {
+    fn is_eq(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion comparing the non equality between two values.
+pub trait IsNe<T> {
+    fn is_ne(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value is within an inclusive range.
+pub trait RangeInclusive<T> {
+    fn in_range(self, left: T, right: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value is strictly less than another one.
+pub trait LessThan<T> {
+    fn less_than(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value is less than or equal to another one.
+pub trait LessThanOrEqual<T> {
+    fn less_than_or_equal(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value is strictly greater than another one.
+pub trait GreaterThan<T> {
+    fn greater_than(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value is greater than or equal to another one.
+pub trait GreaterThanOrEqual<T> {
+    fn greater_than_or_equal(self, expected: T) -> Assertion<u16>;
+}
+
+/// Builds an assertion checking that a value equals one of a given set.
+pub trait OneOf<T> {
+    fn one_of(self, expected: T) -> Assertion<u16>;
+}
+
+fn assertion(predicate: Predicate, left: u16, right: u16, passed: bool) -> Assertion<u16> {
+    Assertion {
+        part: Part::Status,
+        predicate,
+        left,
+        right,
+        passed,
+        elapsed: Duration::default(),
+        candidates: None,
+    }
+}
+
+/// Builds a [`Predicate::OneOf`] assertion. There's no single expected value,
+/// so both `left` and `right` hold the actual value and the full candidate set
+/// is kept in `candidates`.
+fn assertion_one_of(candidates: Vec<u16>, actual: u16, passed: bool) -> Assertion<u16> {
+    Assertion {
+        part: Part::Status,
+        predicate: Predicate::OneOf,
+        left: actual,
+        right: actual,
+        passed,
+        elapsed: Duration::default(),
+        candidates: Some(candidates),
+    }
+}
+
+impl IsEq<u16> for StatusCode {
+    fn is_eq(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(Predicate::Is, expected, actual, actual == expected)
+    }
+}
+
+impl IsEq<StatusCode> for StatusCode {
+    fn is_eq(self, expected: StatusCode) -> Assertion<u16> {
+        self.is_eq(expected.as_u16())
+    }
+}
+
+impl IsNe<u16> for StatusCode {
+    fn is_ne(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(Predicate::IsNot, expected, actual, actual != expected)
+    }
+}
+
+impl IsNe<StatusCode> for StatusCode {
+    fn is_ne(self, expected: StatusCode) -> Assertion<u16> {
+        self.is_ne(expected.as_u16())
+    }
+}
+
+impl RangeInclusive<u16> for StatusCode {
+    fn in_range(self, left: u16, right: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(
+            Predicate::Between,
+            left,
+            actual,
+            (left..=right).contains(&actual),
+        )
+    }
+}
+
+impl RangeInclusive<StatusCode> for StatusCode {
+    fn in_range(self, left: StatusCode, right: StatusCode) -> Assertion<u16> {
+        self.in_range(left.as_u16(), right.as_u16())
+    }
+}
+
+impl LessThan<u16> for StatusCode {
+    fn less_than(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(Predicate::LessThan, expected, actual, actual < expected)
+    }
+}
+
+impl LessThan<StatusCode> for StatusCode {
+    fn less_than(self, expected: StatusCode) -> Assertion<u16> {
+        self.less_than(expected.as_u16())
+    }
+}
+
+impl LessThanOrEqual<u16> for StatusCode {
+    fn less_than_or_equal(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(
+            Predicate::LessThanOrEqual,
+            expected,
+            actual,
+            actual <= expected,
+        )
+    }
+}
+
+impl LessThanOrEqual<StatusCode> for StatusCode {
+    fn less_than_or_equal(self, expected: StatusCode) -> Assertion<u16> {
+        self.less_than_or_equal(expected.as_u16())
+    }
+}
+
+impl GreaterThan<u16> for StatusCode {
+    fn greater_than(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(Predicate::GreaterThan, expected, actual, actual > expected)
+    }
+}
+
+impl GreaterThan<StatusCode> for StatusCode {
+    fn greater_than(self, expected: StatusCode) -> Assertion<u16> {
+        self.greater_than(expected.as_u16())
+    }
+}
+
+impl GreaterThanOrEqual<u16> for StatusCode {
+    fn greater_than_or_equal(self, expected: u16) -> Assertion<u16> {
+        let actual = self.as_u16();
+        assertion(
+            Predicate::GreaterThanOrEqual,
+            expected,
+            actual,
+            actual >= expected,
+        )
+    }
+}
+
+impl GreaterThanOrEqual<StatusCode> for StatusCode {
+    fn greater_than_or_equal(self, expected: StatusCode) -> Assertion<u16> {
+        self.greater_than_or_equal(expected.as_u16())
+    }
+}
+
+impl OneOf<Vec<u16>> for StatusCode {
+    fn one_of(self, expected: Vec<u16>) -> Assertion<u16> {
+        let actual = self.as_u16();
+        let passed = expected.contains(&actual);
+        assertion_one_of(expected, actual, passed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_eq_compares_the_actual_status_code() {
+        assert!(StatusCode::OK.is_eq(200_u16).passed);
+        assert!(!StatusCode::OK.is_eq(404_u16).passed);
+    }
+
+    #[test]
+    fn is_ne_compares_the_actual_status_code() {
+        assert!(StatusCode::OK.is_ne(404_u16).passed);
+        assert!(!StatusCode::OK.is_ne(200_u16).passed);
+    }
+
+    #[test]
+    fn in_range_checks_inclusive_bounds() {
+        assert!(StatusCode::OK.in_range(200_u16, 299_u16).passed);
+        assert!(!StatusCode::NOT_FOUND.in_range(200_u16, 299_u16).passed);
+    }
+
+    #[test]
+    fn less_than_and_less_than_or_equal_compare_the_actual_status_code() {
+        assert!(StatusCode::OK.less_than(404_u16).passed);
+        assert!(!StatusCode::NOT_FOUND.less_than(200_u16).passed);
+        assert!(StatusCode::OK.less_than_or_equal(200_u16).passed);
+        assert!(!StatusCode::NOT_FOUND.less_than_or_equal(200_u16).passed);
+    }
+
+    #[test]
+    fn greater_than_and_greater_than_or_equal_compare_the_actual_status_code() {
+        assert!(StatusCode::NOT_FOUND.greater_than(200_u16).passed);
+        assert!(!StatusCode::OK.greater_than(404_u16).passed);
+        assert!(StatusCode::OK.greater_than_or_equal(200_u16).passed);
+        assert!(!StatusCode::OK.greater_than_or_equal(404_u16).passed);
+    }
+
+    #[test]
+    fn one_of_passes_when_the_actual_status_code_is_in_the_set() {
+        let assertion = StatusCode::NOT_FOUND.one_of(vec![200, 404, 500]);
+
+        assert!(assertion.passed);
+        assert_eq!(assertion.left, 404);
+    }
+
+    #[test]
+    fn one_of_fails_when_the_actual_status_code_is_not_in_the_set() {
+        let assertion = StatusCode::OK.one_of(vec![404, 500]);
+
+        assert!(!assertion.passed);
+    }
+
+    #[test]
+    fn one_of_keeps_the_full_candidate_set_for_reporting() {
+        let expected = vec![200, 404, 500];
+        let assertion = StatusCode::NOT_FOUND.one_of(expected.clone());
+
+        assert_eq!(assertion.candidates, Some(expected));
+    }
+
+    #[test]
+    fn non_one_of_assertions_carry_no_candidate_set() {
+        assert_eq!(StatusCode::OK.is_eq(200_u16).candidates, None);
+    }
+}
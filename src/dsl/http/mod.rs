@@ -31,14 +31,7 @@
 
 mod body;
 mod headers;
-// mod status;
-mod status_bis;
+mod status;
 mod time;
 
-pub use self::{
-    body::JsonBodyDsl,
-    headers::HeadersDsl,
-    // status::{is_client_error, is_server_error, is_success, StatusCodeDsl},
-    status_bis::*,
-    time::TimeDsl,
-};
+pub use self::{body::JsonBodyDsl, headers::HeadersDsl, status::*, time::TimeDsl};
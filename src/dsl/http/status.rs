@@ -2,21 +2,36 @@
 //! assertions against the status of an http response.
 use crate::{
     assertion::{
-        traits::{IsEq, IsNe, RangeInclusive},
+        traits::{
+            GreaterThan, GreaterThanOrEqual, IsEq, IsNe, LessThan, LessThanOrEqual, OneOf,
+            RangeInclusive,
+        },
         Assertion,
     },
-    dsl::{is_between, Expression, Predicate, Range},
+    dsl::{is, is_between, Expression, Predicate, Range},
     grillon::LogSettings,
     StatusCode,
 };
 use std::fmt::Debug;
 
+/// A short-hand function to test if the status code
+/// of the response is in the range of 1xx codes.
+pub fn is_informational() -> Expression<Range<u16>> {
+    is_between(100, 199)
+}
+
 /// A short-hand function to test if the status code
 /// of the response is in the range of 2xx codes.
 pub fn is_success() -> Expression<Range<u16>> {
     is_between(200, 299)
 }
 
+/// A short-hand function to test if the status code
+/// of the response is in the range of 3xx codes.
+pub fn is_redirection() -> Expression<Range<u16>> {
+    is_between(300, 399)
+}
+
 /// A short-hand function to test if the status code
 /// of the response is in the range of 4xx codes.
 pub fn is_client_error() -> Expression<Range<u16>> {
@@ -29,6 +44,125 @@ pub fn is_server_error() -> Expression<Range<u16>> {
     is_between(500, 599)
 }
 
+/// A short-hand function to test if the status code of the response is in the
+/// range of the given class, e.g. `has_class(3)` for any `3xx` redirection code.
+///
+/// # Panics
+///
+/// Panics if `n` is not a valid status class, i.e. not in `1..=5`.
+pub fn has_class(n: u16) -> Expression<Range<u16>> {
+    assert!(
+        (1..=5).contains(&n),
+        "has_class: expected a status class between 1 and 5, got {n}"
+    );
+    is_between(n * 100, n * 100 + 99)
+}
+
+/// A short-hand function to test if the status code of the response is `200 OK`.
+pub fn is_ok() -> Expression<u16> {
+    is(200)
+}
+
+/// A short-hand function to test if the status code of the response is `201 Created`.
+pub fn is_created() -> Expression<u16> {
+    is(201)
+}
+
+/// A short-hand function to test if the status code of the response is `202 Accepted`.
+pub fn is_accepted() -> Expression<u16> {
+    is(202)
+}
+
+/// A short-hand function to test if the status code of the response is `204 No Content`.
+pub fn is_no_content() -> Expression<u16> {
+    is(204)
+}
+
+/// A short-hand function to test if the status code of the response is `206 Partial Content`.
+pub fn is_partial_content() -> Expression<u16> {
+    is(206)
+}
+
+/// A short-hand function to test if the status code of the response is `301 Moved Permanently`.
+pub fn is_moved_permanently() -> Expression<u16> {
+    is(301)
+}
+
+/// A short-hand function to test if the status code of the response is `302 Found`.
+pub fn is_found() -> Expression<u16> {
+    is(302)
+}
+
+/// A short-hand function to test if the status code of the response is `304 Not Modified`.
+pub fn is_not_modified() -> Expression<u16> {
+    is(304)
+}
+
+/// A short-hand function to test if the status code of the response is `400 Bad Request`.
+pub fn is_bad_request() -> Expression<u16> {
+    is(400)
+}
+
+/// A short-hand function to test if the status code of the response is `401 Unauthorized`.
+pub fn is_unauthorized() -> Expression<u16> {
+    is(401)
+}
+
+/// A short-hand function to test if the status code of the response is `403 Forbidden`.
+pub fn is_forbidden() -> Expression<u16> {
+    is(403)
+}
+
+/// A short-hand function to test if the status code of the response is `404 Not Found`.
+pub fn is_not_found() -> Expression<u16> {
+    is(404)
+}
+
+/// A short-hand function to test if the status code of the response is `405 Method Not Allowed`.
+pub fn is_method_not_allowed() -> Expression<u16> {
+    is(405)
+}
+
+/// A short-hand function to test if the status code of the response is `409 Conflict`.
+pub fn is_conflict() -> Expression<u16> {
+    is(409)
+}
+
+/// A short-hand function to test if the status code of the response is `410 Gone`.
+pub fn is_gone() -> Expression<u16> {
+    is(410)
+}
+
+/// A short-hand function to test if the status code of the response is `422 Unprocessable Entity`.
+pub fn is_unprocessable_entity() -> Expression<u16> {
+    is(422)
+}
+
+/// A short-hand function to test if the status code of the response is `429 Too Many Requests`.
+pub fn is_too_many_requests() -> Expression<u16> {
+    is(429)
+}
+
+/// A short-hand function to test if the status code of the response is `500 Internal Server Error`.
+pub fn is_internal_server_error() -> Expression<u16> {
+    is(500)
+}
+
+/// A short-hand function to test if the status code of the response is `501 Not Implemented`.
+pub fn is_not_implemented() -> Expression<u16> {
+    is(501)
+}
+
+/// A short-hand function to test if the status code of the response is `502 Bad Gateway`.
+pub fn is_bad_gateway() -> Expression<u16> {
+    is(502)
+}
+
+/// A short-hand function to test if the status code of the response is `503 Service Unavailable`.
+pub fn is_service_unavailable() -> Expression<u16> {
+    is(503)
+}
+
 /// Http status DSL to assert the status code of a response.
 ///
 /// ```rust
@@ -73,6 +207,12 @@ impl StatusCodeDsl<StatusCode> for StatusCode {
         match predicate {
             Predicate::Is => self.is(actual).assert(log_settings),
             Predicate::IsNot => self.is_not(actual).assert(log_settings),
+            Predicate::LessThan => self.is_less_than(actual).assert(log_settings),
+            Predicate::LessThanOrEqual => self.is_less_than_or_equal(actual).assert(log_settings),
+            Predicate::GreaterThan => self.is_greater_than(actual).assert(log_settings),
+            Predicate::GreaterThanOrEqual => {
+                self.is_greater_than_or_equal(actual).assert(log_settings)
+            }
             _ => unimplemented!(),
         }
     }
@@ -90,6 +230,12 @@ impl StatusCodeDsl<StatusCode> for u16 {
         match predicate {
             Predicate::Is => self.is(actual).assert(log_settings),
             Predicate::IsNot => self.is_not(actual).assert(log_settings),
+            Predicate::LessThan => self.is_less_than(actual).assert(log_settings),
+            Predicate::LessThanOrEqual => self.is_less_than_or_equal(actual).assert(log_settings),
+            Predicate::GreaterThan => self.is_greater_than(actual).assert(log_settings),
+            Predicate::GreaterThanOrEqual => {
+                self.is_greater_than_or_equal(actual).assert(log_settings)
+            }
             _ => unimplemented!(),
         }
     }
@@ -159,6 +305,63 @@ impl StatusCodeDslEquality<StatusCode> for u16 {
     }
 }
 
+/// Http status DSL to assert the ordering of a status code relative to
+/// another one.
+pub trait StatusCodeDslOrdering<T>: StatusCodeDsl<T>
+where
+    T: Debug,
+    Self: Debug + Sized,
+{
+    /// Builds an assertion comparing if a status code is strictly less than
+    /// another one.
+    fn is_less_than(self, actual: T) -> Self::Assertion;
+    /// Builds an assertion comparing if a status code is less than or equal
+    /// to another one.
+    fn is_less_than_or_equal(self, actual: T) -> Self::Assertion;
+    /// Builds an assertion comparing if a status code is strictly greater
+    /// than another one.
+    fn is_greater_than(self, actual: T) -> Self::Assertion;
+    /// Builds an assertion comparing if a status code is greater than or
+    /// equal to another one.
+    fn is_greater_than_or_equal(self, actual: T) -> Self::Assertion;
+}
+
+impl StatusCodeDslOrdering<StatusCode> for StatusCode {
+    fn is_less_than(self, actual: StatusCode) -> Self::Assertion {
+        actual.less_than(self)
+    }
+
+    fn is_less_than_or_equal(self, actual: StatusCode) -> Self::Assertion {
+        actual.less_than_or_equal(self)
+    }
+
+    fn is_greater_than(self, actual: StatusCode) -> Self::Assertion {
+        actual.greater_than(self)
+    }
+
+    fn is_greater_than_or_equal(self, actual: StatusCode) -> Self::Assertion {
+        actual.greater_than_or_equal(self)
+    }
+}
+
+impl StatusCodeDslOrdering<StatusCode> for u16 {
+    fn is_less_than(self, actual: StatusCode) -> Self::Assertion {
+        actual.less_than(self)
+    }
+
+    fn is_less_than_or_equal(self, actual: StatusCode) -> Self::Assertion {
+        actual.less_than_or_equal(self)
+    }
+
+    fn is_greater_than(self, actual: StatusCode) -> Self::Assertion {
+        actual.greater_than(self)
+    }
+
+    fn is_greater_than_or_equal(self, actual: StatusCode) -> Self::Assertion {
+        actual.greater_than_or_equal(self)
+    }
+}
+
 /// Http status DSL to assert the status code of a response is in
 /// the given inclusive range.
 pub trait StatusCodeDslBetween<T>: StatusCodeDsl<T>
@@ -181,4 +384,135 @@ impl StatusCodeDslBetween<StatusCode> for Range<u16> {
     fn is_between(self, actual: StatusCode) -> Self::Assertion {
         actual.in_range(self.left, self.right)
     }
-}
\ No newline at end of file
+}
+
+impl StatusCodeDsl<StatusCode> for Vec<u16> {
+    type Assertion = Assertion<u16>;
+
+    fn eval(
+        self,
+        actual: StatusCode,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<u16> {
+        match predicate {
+            Predicate::OneOf => self.is_one_of(actual).assert(log_settings),
+            _ => unimplemented!(),
+        }
+    }
+}
+
+impl StatusCodeDsl<StatusCode> for Vec<StatusCode> {
+    type Assertion = Assertion<u16>;
+
+    fn eval(
+        self,
+        actual: StatusCode,
+        predicate: Predicate,
+        log_settings: &LogSettings,
+    ) -> Assertion<u16> {
+        match predicate {
+            Predicate::OneOf => self.is_one_of(actual).assert(log_settings),
+            _ => unimplemented!(),
+        }
+    }
+}
+
+/// Http status DSL to assert the status code of a response is one of a
+/// given set of codes.
+pub trait StatusCodeDslOneOf<T>: StatusCodeDsl<T>
+where
+    T: Debug,
+    Self: Debug + Sized,
+{
+    /// Builds an assertion to check if a status code is one of the given
+    /// set of codes.
+    fn is_one_of(self, actual: T) -> Self::Assertion;
+}
+
+impl StatusCodeDslOneOf<StatusCode> for Vec<u16> {
+    fn is_one_of(self, actual: StatusCode) -> Self::Assertion {
+        actual.one_of(self)
+    }
+}
+
+impl StatusCodeDslOneOf<StatusCode> for Vec<StatusCode> {
+    fn is_one_of(self, actual: StatusCode) -> Self::Assertion {
+        actual.one_of(self.into_iter().map(|code| code.as_u16()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_helpers_build_between_expressions_for_their_class() {
+        let cases: Vec<(Expression<Range<u16>>, u16, u16)> = vec![
+            (is_informational(), 100, 199),
+            (is_success(), 200, 299),
+            (is_redirection(), 300, 399),
+            (is_client_error(), 400, 499),
+            (is_server_error(), 500, 599),
+        ];
+
+        for (expression, left, right) in cases {
+            assert_eq!(expression.predicate, Predicate::Between);
+            assert_eq!(expression.value.left, left);
+            assert_eq!(expression.value.right, right);
+        }
+    }
+
+    #[test]
+    fn has_class_builds_the_matching_between_expression() {
+        let expression = has_class(4);
+
+        assert_eq!(expression.predicate, Predicate::Between);
+        assert_eq!(expression.value.left, 400);
+        assert_eq!(expression.value.right, 499);
+    }
+
+    #[test]
+    #[should_panic(expected = "has_class: expected a status class between 1 and 5, got 0")]
+    fn has_class_panics_below_the_valid_range() {
+        has_class(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "has_class: expected a status class between 1 and 5, got 6")]
+    fn has_class_panics_above_the_valid_range() {
+        has_class(6);
+    }
+
+    #[test]
+    fn named_constructors_build_is_expressions_for_the_right_code() {
+        let cases: Vec<(Expression<u16>, u16)> = vec![
+            (is_ok(), 200),
+            (is_created(), 201),
+            (is_accepted(), 202),
+            (is_no_content(), 204),
+            (is_partial_content(), 206),
+            (is_moved_permanently(), 301),
+            (is_found(), 302),
+            (is_not_modified(), 304),
+            (is_bad_request(), 400),
+            (is_unauthorized(), 401),
+            (is_forbidden(), 403),
+            (is_not_found(), 404),
+            (is_method_not_allowed(), 405),
+            (is_conflict(), 409),
+            (is_gone(), 410),
+            (is_unprocessable_entity(), 422),
+            (is_too_many_requests(), 429),
+            (is_internal_server_error(), 500),
+            (is_not_implemented(), 501),
+            (is_bad_gateway(), 502),
+            (is_service_unavailable(), 503),
+        ];
+
+        for (expression, code) in cases {
+            assert_eq!(expression.predicate, Predicate::Is);
+            assert_eq!(expression.value, code);
+        }
+    }
+}
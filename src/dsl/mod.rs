@@ -0,0 +1,117 @@
+//! The `dsl` module provides the building blocks shared by every grillon
+//! assertion: the [`Predicate`] a value is checked against, the [`Range`]
+//! and [`Expression`] types an assertion is built from, and the top-level
+//! constructors (`is`, `is_between`, ...) used to build them.
+pub mod http;
+
+/// The comparison an [`Expression`] is evaluated with against the actual
+/// value of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    /// Equality.
+    Is,
+    /// Inequality.
+    IsNot,
+    /// Inclusive range membership.
+    Between,
+    /// Strictly less than.
+    LessThan,
+    /// Less than or equal to.
+    LessThanOrEqual,
+    /// Strictly greater than.
+    GreaterThan,
+    /// Greater than or equal to.
+    GreaterThanOrEqual,
+    /// Set membership.
+    OneOf,
+}
+
+/// An identifier for which part of an http response an assertion was
+/// evaluated against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    Status,
+    Headers,
+    Body,
+    Time,
+    JsonPath,
+}
+
+/// An inclusive range of values, bounded by `left` and `right`.
+#[derive(Debug, Clone, Copy)]
+pub struct Range<T> {
+    pub left: T,
+    pub right: T,
+}
+
+/// A declarative assertion, carrying the expected value(s) and the
+/// [`Predicate`] to evaluate it with.
+#[derive(Debug, Clone)]
+pub struct Expression<T> {
+    pub predicate: Predicate,
+    pub value: T,
+}
+
+/// Asserts the actual value is equal to `value`.
+pub fn is<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::Is,
+        value,
+    }
+}
+
+/// Asserts the actual value is not equal to `value`.
+pub fn is_not<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::IsNot,
+        value,
+    }
+}
+
+/// Asserts the actual value falls within the inclusive range `[left, right]`.
+pub fn is_between<T>(left: T, right: T) -> Expression<Range<T>> {
+    Expression {
+        predicate: Predicate::Between,
+        value: Range { left, right },
+    }
+}
+
+/// Asserts the actual value is strictly less than `value`.
+pub fn is_less_than<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::LessThan,
+        value,
+    }
+}
+
+/// Asserts the actual value is less than or equal to `value`.
+pub fn is_less_than_or_equal<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::LessThanOrEqual,
+        value,
+    }
+}
+
+/// Asserts the actual value is strictly greater than `value`.
+pub fn is_greater_than<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::GreaterThan,
+        value,
+    }
+}
+
+/// Asserts the actual value is greater than or equal to `value`.
+pub fn is_greater_than_or_equal<T>(value: T) -> Expression<T> {
+    Expression {
+        predicate: Predicate::GreaterThanOrEqual,
+        value,
+    }
+}
+
+/// Asserts the actual value equals one of the given `values`.
+pub fn is_one_of<T>(values: Vec<T>) -> Expression<Vec<T>> {
+    Expression {
+        predicate: Predicate::OneOf,
+        value: values,
+    }
+}
@@ -0,0 +1,14 @@
+//! The `grillon` module holds the client-facing configuration types, such as
+//! [`LogSettings`], used to control how assertion results are reported.
+
+/// Controls how assertion results are reported once evaluated.
+#[derive(Debug, Clone)]
+pub enum LogSettings {
+    /// Human-readable output (the default).
+    Human,
+    /// Machine-readable JSON output.
+    Json,
+    /// A custom, template-driven one-line-per-assertion output. See
+    /// [`crate::assertion::format`] for the supported placeholders.
+    Custom(String),
+}